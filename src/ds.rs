@@ -0,0 +1,5 @@
+pub mod dsu;
+pub mod fenwick;
+
+pub use dsu::*;
+pub use fenwick::*;
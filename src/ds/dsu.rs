@@ -184,11 +184,7 @@ impl DSU {
     /// assert_eq!(dsu.size(1), Some(1));
     /// ```
     pub fn size(&self, a: DSUIdx) -> Option<usize> {
-        if let Some(r) = self.root(a) {
-            Some(-self.root_or_size[r] as usize)
-        } else {
-            None
-        }
+        self.root(a).map(|r| -self.root_or_size[r] as usize)
     }
 
     /// Return the size of the group that contains `a`.
@@ -232,7 +228,7 @@ impl DSU {
         for i in 0..n {
             g[self.root(i).unwrap()].push(i);
         }
-        g.iter().cloned().filter(|x| !x.is_empty()).collect()
+        g.iter().filter(|x| !x.is_empty()).cloned().collect()
     }
 
     /// Return groups list.
@@ -253,6 +249,6 @@ impl DSU {
         for i in 0..n {
             g[self.root_mut(i).unwrap()].push(i);
         }
-        g.iter().cloned().filter(|x| !x.is_empty()).collect()
+        g.iter().filter(|x| !x.is_empty()).cloned().collect()
     }
 }
@@ -0,0 +1,73 @@
+use std::ops::{Add, Sub};
+
+use crate::math::Zero;
+
+/// Fenwick tree (binary indexed tree) for point-update / prefix-query
+/// workloads over any commutative group.
+///
+/// `T` only needs an identity ([`Zero`]) and a combine operation (`Add`),
+/// plus its inverse (`Sub`) to support [`range_sum`](Self::range_sum).
+/// This covers ordinary integer sums, and also xor-group queries by
+/// wrapping values in a newtype whose `Add`/`Sub` both perform xor (its
+/// own inverse).
+#[derive(Debug, Clone)]
+pub struct FenwickTree<T> {
+    data: Vec<T>,
+}
+
+impl<T: Zero + Add<Output = T> + Sub<Output = T> + Clone + Copy> FenwickTree<T> {
+    /// Create a Fenwick tree over `n` elements, all initialized to the
+    /// group identity.
+    ///
+    /// **Complexity** `O(n)`
+    pub fn new(n: usize) -> Self {
+        Self {
+            data: vec![T::zero(); n + 1],
+        }
+    }
+
+    /// Combine `delta` into the element at `i`.
+    ///
+    /// **Complexity** `O(log n)`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use procon::ds::FenwickTree;
+    ///
+    /// fn main() {
+    ///     let mut fenwick = FenwickTree::new(4);
+    ///     fenwick.add(0, 1);
+    ///     fenwick.add(2, 3);
+    ///     assert_eq!(fenwick.sum(3), 4);
+    ///     assert_eq!(fenwick.range_sum(1, 3), 3);
+    /// }
+    /// ```
+    pub fn add(&mut self, i: usize, delta: T) {
+        let mut i = i + 1;
+        while i < self.data.len() {
+            self.data[i] = self.data[i] + delta;
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    /// Get the combined value of the prefix `[0, i)`.
+    ///
+    /// **Complexity** `O(log n)`
+    pub fn sum(&self, i: usize) -> T {
+        let mut i = i;
+        let mut s = T::zero();
+        while i > 0 {
+            s = s + self.data[i];
+            i -= i & i.wrapping_neg();
+        }
+        s
+    }
+
+    /// Get the combined value of the range `[l, r)`.
+    ///
+    /// **Complexity** `O(log n)`
+    pub fn range_sum(&self, l: usize, r: usize) -> T {
+        self.sum(r) - self.sum(l)
+    }
+}
@@ -1,8 +1,20 @@
+pub mod astar;
+pub mod bellman_ford;
 pub mod bfs;
+pub mod csr;
 pub mod dijkstra;
+pub mod hld;
+pub mod mst;
+pub mod scc;
 
+pub use astar::*;
+pub use bellman_ford::*;
 pub use bfs::*;
+pub use csr::*;
 pub use dijkstra::*;
+pub use hld::*;
+pub use mst::*;
+pub use scc::*;
 
 pub type NodeIdx = usize;
 pub type EdgeIdx = usize;
@@ -146,6 +158,82 @@ impl<D> Graph<D> {
     pub fn edges(&self) -> &Vec<Edge> {
         &self.edges
     }
+
+    /// Build a [`Csr`] view of the current out-edges.
+    ///
+    /// Traversals over the result avoid the per-node `Vec` indirection
+    /// that [`out_edges`](Self::out_edges) pays on every call.
+    ///
+    /// **Complexity** `O(V+E)`
+    ///
+    /// # Example
+    ///
+    /// `Csr` implements [`AdjacencyList`] just like [`Graph`], so
+    /// traversals give identical results over either representation:
+    ///
+    /// ```
+    /// use procon::graph::*;
+    ///
+    /// fn main() {
+    ///     let mut graph = DiGraph::with_nodes(4);
+    ///     graph.add_edge(Edge::new(0, 1));
+    ///     graph.add_edge(Edge::new(1, 2));
+    ///     graph.add_edge(Edge::new(3, 0));
+    ///     graph.add_edge(Edge::new(3, 1));
+    ///     let csr = graph.build_csr();
+    ///
+    ///     let bfs_graph = BFS::with_graph(&graph, 0);
+    ///     let bfs_csr = BFS::with_graph(&csr, 0);
+    ///     assert_eq!(bfs_graph.distance(), bfs_csr.distance());
+    ///
+    ///     let dijkstra_graph = Dijkstra::with_graph(&graph, 0, |_| 1);
+    ///     let dijkstra_csr = Dijkstra::with_graph(&csr, 0, |_| 1);
+    ///     assert_eq!(dijkstra_graph.distance(), dijkstra_csr.distance());
+    /// }
+    /// ```
+    pub fn build_csr(&self) -> Csr {
+        let n = self.n_nodes();
+        let mut row = vec![0usize; n + 1];
+        for node in 0..n {
+            row[node + 1] = row[node] + self.nodes[node].out_edges.len();
+        }
+
+        let mut target = vec![0; row[n]];
+        let mut edge_idx = vec![0; row[n]];
+        for node in 0..n {
+            for (i, (edge, idx)) in self.nodes[node].out_edges.iter().enumerate() {
+                target[row[node] + i] = edge.target;
+                edge_idx[row[node] + i] = *idx;
+            }
+        }
+
+        Csr {
+            row,
+            target,
+            edge_idx,
+        }
+    }
+}
+
+impl<D> AdjacencyList for Graph<D> {
+    type Edges<'a>
+        = std::iter::Map<
+        std::slice::Iter<'a, (Edge, EdgeIdx)>,
+        fn(&(Edge, EdgeIdx)) -> (NodeIdx, EdgeIdx),
+    >
+    where
+        Self: 'a;
+
+    fn n_nodes(&self) -> usize {
+        self.n_nodes()
+    }
+
+    fn out_edges(&self, node: NodeIdx) -> Self::Edges<'_> {
+        self.nodes[node]
+            .out_edges
+            .iter()
+            .map(|(edge, edge_idx)| (edge.target, *edge_idx))
+    }
 }
 
 impl DiGraph {
@@ -0,0 +1,140 @@
+use std::{cmp::Reverse, collections::BinaryHeap, ops::Add};
+
+use super::*;
+use crate::TotalOrd;
+
+/// A* search.
+///
+/// Finds the shortest path from `root` to a single `goal` faster than
+/// [`Dijkstra`] when an admissible heuristic (never overestimating the
+/// remaining cost to `goal`) is available.
+#[derive(Debug, Default)]
+pub struct AStar<T: PartialEq + PartialOrd> {
+    root: NodeIdx,
+    goal: NodeIdx,
+    distance: Vec<Option<T>>,
+    through: Vec<Option<EdgeIdx>>,
+    next: BinaryHeap<(Reverse<TotalOrd<T>>, NodeIdx)>,
+}
+
+impl<T: Default + PartialEq + PartialOrd + Clone + Copy + From<i32> + Add<Output = T>> AStar<T> {
+    /// Get root node.
+    ///
+    /// **Complexity** `O(1)`
+    pub fn root(&self) -> NodeIdx {
+        self.root
+    }
+
+    /// Get goal node.
+    ///
+    /// **Complexity** `O(1)`
+    pub fn goal(&self) -> NodeIdx {
+        self.goal
+    }
+
+    /// Get path distance.
+    ///
+    /// **Complexity** `O(1)`
+    pub fn distance(&self) -> &Vec<Option<T>> {
+        &self.distance
+    }
+
+    /// Get path through.
+    ///
+    /// **Complexity** `O(1)`
+    pub fn through(&self) -> &Vec<Option<EdgeIdx>> {
+        &self.through
+    }
+
+    /// Create AStar with graph, root node and goal node.
+    ///
+    /// `heuristic` must be admissible, i.e. never overestimate the true
+    /// remaining distance to `goal`, for the returned distance to `goal`
+    /// to be optimal.
+    ///
+    /// **Complexity** `O((E+V)logV)`
+    ///
+    /// # Example
+    ///
+    /// An undirected 4-cycle, so the direct edge `3-0` must be usable from
+    /// either endpoint even though it's stored as `Edge::new(3, 0)`:
+    ///
+    /// ```
+    /// use procon::graph::*;
+    ///
+    /// fn main() {
+    ///     let n_nodes = 4;
+    ///     let edges = vec![
+    ///         Edge::new(0, 1),
+    ///         Edge::new(1, 2),
+    ///         Edge::new(2, 3),
+    ///         Edge::new(3, 0),
+    ///     ];
+    ///     let mut graph = UnGraph::with_nodes(n_nodes);
+    ///     for edge in edges {
+    ///         graph.add_edge(edge);
+    ///     }
+    ///
+    ///     let algo = AStar::with_graph(&graph, 0, 3, |_| 1, |_| 0);
+    ///     let distance = algo.distance();
+    ///     let through = algo.through();
+    ///
+    ///     assert_eq!(distance[3], Some(1));
+    ///     assert_eq!(through[3], Some(3));
+    /// }
+    /// ```
+    pub fn with_graph<D, F: Fn(EdgeIdx) -> T, H: Fn(NodeIdx) -> T>(
+        graph: &Graph<D>,
+        root: NodeIdx,
+        goal: NodeIdx,
+        edge_dist: F,
+        heuristic: H,
+    ) -> Self {
+        let mut algo = Self::default();
+        algo.read(graph, root, goal, edge_dist, heuristic);
+        algo
+    }
+
+    /// Run AStar with graph, root node and goal node.
+    ///
+    /// **Complexity** `O((E+V)logV)`
+    pub fn read<D, F: Fn(EdgeIdx) -> T, H: Fn(NodeIdx) -> T>(
+        &mut self,
+        graph: &Graph<D>,
+        root: NodeIdx,
+        goal: NodeIdx,
+        edge_distance: F,
+        heuristic: H,
+    ) {
+        self.distance.clear();
+        self.through.clear();
+        self.next.clear();
+
+        self.root = root;
+        self.goal = goal;
+        self.distance.resize(graph.n_nodes(), None);
+        self.through.resize(graph.n_nodes(), None);
+
+        self.distance[root] = Some(T::from(0));
+        self.next.push((Reverse(TotalOrd(heuristic(root))), root));
+
+        while let Some((_, node)) = self.next.pop() {
+            if node == goal {
+                break;
+            }
+            let dist = self.distance[node].unwrap();
+            for (edge, edge_idx) in graph.out_edges(node) {
+                let next_dist = dist + edge_distance(*edge_idx);
+                if let Some(prev_dist) = self.distance[edge.target] {
+                    if prev_dist <= next_dist {
+                        continue;
+                    }
+                }
+                self.distance[edge.target] = Some(next_dist);
+                self.through[edge.target] = Some(*edge_idx);
+                let priority = next_dist + heuristic(edge.target);
+                self.next.push((Reverse(TotalOrd(priority)), edge.target));
+            }
+        }
+    }
+}
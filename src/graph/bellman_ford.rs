@@ -0,0 +1,148 @@
+use std::ops::Add;
+
+use super::*;
+
+/// Indicates that a negative cycle was found while running [`BellmanFord`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NegativeCycle;
+
+/// Bellman-Ford algorithm.
+///
+/// Unlike [`Dijkstra`], this handles graphs with negative edge weights, and
+/// detects negative cycles reachable from the root.
+#[derive(Debug, Default)]
+pub struct BellmanFord<T: PartialEq + PartialOrd> {
+    root: NodeIdx,
+    distance: Vec<Option<T>>,
+    through: Vec<Option<EdgeIdx>>,
+}
+
+impl<T: Default + PartialEq + PartialOrd + Clone + Copy + From<i32> + Add<Output = T>>
+    BellmanFord<T>
+{
+    /// Get root node.
+    ///
+    /// **Complexity** `O(1)`
+    pub fn root(&self) -> NodeIdx {
+        self.root
+    }
+
+    /// Get path distance.
+    ///
+    /// **Complexity** `O(1)`
+    pub fn distance(&self) -> &Vec<Option<T>> {
+        &self.distance
+    }
+
+    /// Get path through.
+    ///
+    /// **Complexity** `O(1)`
+    pub fn through(&self) -> &Vec<Option<EdgeIdx>> {
+        &self.through
+    }
+
+    /// Create BellmanFord with graph and root node.
+    ///
+    /// Returns `Err(NegativeCycle)` if a negative cycle reachable from
+    /// `root` exists, in which case distances are not well-defined.
+    ///
+    /// **Complexity** `O(VE)`
+    ///
+    /// # Example
+    ///
+    /// Relaxation over an `UnGraph` needs to walk each edge both ways, not
+    /// just in the direction it was originally added, so this example uses
+    /// an undirected triangle to exercise that:
+    ///
+    /// ```
+    /// use procon::graph::*;
+    ///
+    /// fn main() {
+    ///     let n_nodes = 3;
+    ///     // `Edge::new(1, 2)` and `Edge::new(0, 1)` are stored pointing
+    ///     // toward the root; reaching node 1 from node 2 and node 0 from
+    ///     // node 1 means traversing them in reverse.
+    ///     let edges = vec![Edge::new(0, 1), Edge::new(1, 2), Edge::new(2, 0)];
+    ///     let dist = vec![1, 1, 5];
+    ///     let mut graph = UnGraph::with_nodes(n_nodes);
+    ///     for edge in edges {
+    ///         graph.add_edge(edge);
+    ///     }
+    ///
+    ///     let algo = BellmanFord::with_graph(&graph, 2, |e| dist[e]).unwrap();
+    ///     let distance = algo.distance();
+    ///
+    ///     assert_eq!(distance[2], Some(0));
+    ///     assert_eq!(distance[1], Some(1));
+    ///     assert_eq!(distance[0], Some(2));
+    /// }
+    /// ```
+    pub fn with_graph<D, F: Fn(EdgeIdx) -> T>(
+        graph: &Graph<D>,
+        root: NodeIdx,
+        edge_dist: F,
+    ) -> Result<Self, NegativeCycle> {
+        let mut algo = Self::default();
+        algo.read(graph, root, edge_dist)?;
+        Ok(algo)
+    }
+
+    /// Run BellmanFord with graph and root node.
+    ///
+    /// Returns `Err(NegativeCycle)` if a negative cycle reachable from
+    /// `root` exists.
+    ///
+    /// **Complexity** `O(VE)`
+    pub fn read<D, F: Fn(EdgeIdx) -> T>(
+        &mut self,
+        graph: &Graph<D>,
+        root: NodeIdx,
+        edge_distance: F,
+    ) -> Result<(), NegativeCycle> {
+        self.distance.clear();
+        self.through.clear();
+
+        self.root = root;
+        self.distance.resize(graph.n_nodes(), None);
+        self.through.resize(graph.n_nodes(), None);
+
+        self.distance[root] = Some(T::from(0));
+
+        let n = graph.n_nodes();
+        for _ in 1..n {
+            let mut updated = false;
+            for node in 0..n {
+                if let Some(source_dist) = self.distance[node] {
+                    for (edge, edge_idx) in graph.out_edges(node) {
+                        let next_dist = source_dist + edge_distance(*edge_idx);
+                        if self.distance[edge.target].is_none()
+                            || next_dist < self.distance[edge.target].unwrap()
+                        {
+                            self.distance[edge.target] = Some(next_dist);
+                            self.through[edge.target] = Some(*edge_idx);
+                            updated = true;
+                        }
+                    }
+                }
+            }
+            if !updated {
+                break;
+            }
+        }
+
+        for node in 0..n {
+            if let Some(source_dist) = self.distance[node] {
+                for (edge, edge_idx) in graph.out_edges(node) {
+                    let next_dist = source_dist + edge_distance(*edge_idx);
+                    if self.distance[edge.target].is_none()
+                        || next_dist < self.distance[edge.target].unwrap()
+                    {
+                        return Err(NegativeCycle);
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
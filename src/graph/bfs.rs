@@ -8,7 +8,7 @@ pub struct BFS {
     root: NodeIdx,
     distance: Vec<Option<usize>>,
     through: Vec<Option<EdgeIdx>>,
-    next: VecDeque<EdgeIdx>,
+    next: VecDeque<NodeIdx>,
 }
 
 impl BFS {
@@ -67,7 +67,7 @@ impl BFS {
     ///     assert_eq!(through[3], None);
     /// }
     /// ```
-    pub fn with_graph<D>(graph: &Graph<D>, root: NodeIdx) -> Self {
+    pub fn with_graph<G: AdjacencyList>(graph: &G, root: NodeIdx) -> Self {
         let mut algo = Self::default();
         algo.read(graph, root);
         algo
@@ -75,8 +75,126 @@ impl BFS {
 
     /// Run BFS with graph and root node.
     ///
+    /// Accepts either a [`Graph`] or its [`Csr`] view through the
+    /// [`AdjacencyList`] trait, so traversals can run over whichever
+    /// representation suits the input size.
+    ///
     /// **Complexity** `O(E)`
-    pub fn read<D>(&mut self, graph: &Graph<D>, root: NodeIdx) {
+    pub fn read<G: AdjacencyList>(&mut self, graph: &G, root: NodeIdx) {
+        self.distance.clear();
+        self.through.clear();
+        self.next.clear();
+
+        self.root = root;
+        self.distance.resize(graph.n_nodes(), None);
+        self.through.resize(graph.n_nodes(), None);
+
+        self.distance[root] = Some(0);
+        self.next.push_back(root);
+
+        while let Some(node) = self.next.pop_front() {
+            let dist = self.distance[node].unwrap();
+            for (target, edge_idx) in graph.out_edges(node) {
+                if self.distance[target].is_none() {
+                    self.distance[target] = Some(dist + 1);
+                    self.through[target] = Some(edge_idx);
+                    self.next.push_back(target);
+                }
+            }
+        }
+    }
+}
+
+/// 0-1 BFS.
+///
+/// Computes shortest distances in `O(V+E)` for graphs whose edges are all
+/// weighted 0 or 1, avoiding the `O((E+V)logV)` heap used by [`Dijkstra`].
+#[derive(Debug, Default)]
+pub struct ZeroOneBFS {
+    root: NodeIdx,
+    distance: Vec<Option<usize>>,
+    through: Vec<Option<EdgeIdx>>,
+    next: VecDeque<NodeIdx>,
+}
+
+impl ZeroOneBFS {
+    /// Get root node.
+    ///
+    /// **Complexity** `O(1)`
+    pub fn root(&self) -> NodeIdx {
+        self.root
+    }
+
+    /// Get path distance.
+    ///
+    /// **Complexity** `O(1)`
+    pub fn distance(&self) -> &Vec<Option<usize>> {
+        &self.distance
+    }
+
+    /// Get path through.
+    ///
+    /// **Complexity** `O(1)`
+    pub fn through(&self) -> &Vec<Option<EdgeIdx>> {
+        &self.through
+    }
+
+    /// Create a 0-1 BFS with graph and root node.
+    ///
+    /// `edge_weight` must only return `0` or `1`.
+    ///
+    /// **Complexity** `O(V+E)`
+    ///
+    /// # Example
+    ///
+    /// This example adds an edge pointing toward the root and checks
+    /// that the 0-1 BFS still reaches it, since an `UnGraph` edge is
+    /// traversable from both endpoints no matter which way it was added:
+    ///
+    /// ```
+    /// use procon::graph::*;
+    ///
+    /// fn main() {
+    ///     let n_nodes = 6;
+    ///     // `Edge::new(4, 1)` is stored source=4, target=1, i.e. the
+    ///     // reverse of the direction BFS needs to traverse it in.
+    ///     let edges = vec![Edge::new(0, 1), Edge::new(4, 1), Edge::new(4, 5)];
+    ///     let weight = [1, 0, 0];
+    ///     let mut graph = UnGraph::with_nodes(n_nodes);
+    ///     for edge in edges {
+    ///         graph.add_edge(edge);
+    ///     }
+    ///
+    ///     let algo = ZeroOneBFS::with_graph(&graph, 0, |e| weight[e]);
+    ///     let distance = algo.distance();
+    ///
+    ///     assert_eq!(distance[0], Some(0));
+    ///     assert_eq!(distance[1], Some(1));
+    ///     assert_eq!(distance[4], Some(1));
+    ///     assert_eq!(distance[5], Some(1));
+    /// }
+    /// ```
+    pub fn with_graph<D, F: Fn(EdgeIdx) -> u8>(
+        graph: &Graph<D>,
+        root: NodeIdx,
+        edge_weight: F,
+    ) -> Self {
+        let mut algo = Self::default();
+        algo.read(graph, root, edge_weight);
+        algo
+    }
+
+    /// Run a 0-1 BFS with graph and root node.
+    ///
+    /// `edge_weight` must only return `0` or `1`.
+    ///
+    /// **Complexity** `O(V+E)`
+    pub fn read<D, F: Fn(EdgeIdx) -> u8>(
+        &mut self,
+        graph: &Graph<D>,
+        root: NodeIdx,
+        edge_weight: F,
+    ) {
         self.distance.clear();
         self.through.clear();
         self.next.clear();
@@ -85,22 +203,45 @@ impl BFS {
         self.distance.resize(graph.n_nodes(), None);
         self.through.resize(graph.n_nodes(), None);
 
+        // A node can be pushed more than once with a stale tentative
+        // distance; `done` lets the first (optimal) pop win.
+        let mut done = vec![false; graph.n_nodes()];
+
         self.distance[root] = Some(0);
         for (edge, edge_idx) in graph.out_edges(root) {
-            self.distance[edge.target] = Some(1);
-            self.through[edge.target] = Some(*edge_idx);
-            self.next.push_back(*edge_idx);
+            self.relax(edge, *edge_idx, 0, &edge_weight);
         }
 
-        let edges = graph.edges();
+        while let Some(node) = self.next.pop_front() {
+            if done[node] {
+                continue;
+            }
+            done[node] = true;
 
-        while let Some(curr) = self.next.pop_front() {
-            for (edge, edge_idx) in graph.out_edges(edges[curr].target) {
-                if self.distance[edge.target].is_none() {
-                    self.distance[edge.target] = Some(1 + self.distance[edge.source].unwrap());
-                    self.through[edge.target] = Some(*edge_idx);
-                    self.next.push_back(*edge_idx);
-                }
+            let dist = self.distance[node].unwrap();
+            for (edge, edge_idx) in graph.out_edges(node) {
+                self.relax(edge, *edge_idx, dist, &edge_weight);
+            }
+        }
+    }
+
+    fn relax<F: Fn(EdgeIdx) -> u8>(
+        &mut self,
+        edge: &Edge,
+        edge_idx: EdgeIdx,
+        dist: usize,
+        edge_weight: &F,
+    ) {
+        let w = edge_weight(edge_idx);
+        assert!(w <= 1, "edge weight must be 0 or 1");
+        let next_dist = dist + w as usize;
+        if self.distance[edge.target].is_none() || next_dist < self.distance[edge.target].unwrap() {
+            self.distance[edge.target] = Some(next_dist);
+            self.through[edge.target] = Some(edge_idx);
+            if w == 0 {
+                self.next.push_front(edge.target);
+            } else {
+                self.next.push_back(edge.target);
             }
         }
     }
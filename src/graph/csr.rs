@@ -0,0 +1,54 @@
+use super::*;
+
+/// A read-only adjacency view, implemented both by [`Graph`] and by its
+/// [`Csr`] view, so hot traversal loops ([`BFS`], [`Dijkstra`]) can run
+/// over either representation.
+pub trait AdjacencyList {
+    type Edges<'a>: Iterator<Item = (NodeIdx, EdgeIdx)>
+    where
+        Self: 'a;
+
+    /// Get the number of nodes.
+    fn n_nodes(&self) -> usize;
+
+    /// Iterate over `(target, edge index)` pairs for a node's out-edges.
+    fn out_edges(&self, node: NodeIdx) -> Self::Edges<'_>;
+}
+
+/// Compressed Sparse Row adjacency view of a [`Graph`], built once via
+/// [`Graph::build_csr`].
+///
+/// `Graph` stores one `Vec<(Edge, EdgeIdx)>` per node, which costs a heap
+/// allocation per vertex and scatters traversals across memory. `Csr`
+/// instead stores every out-edge in two flat arrays (`target`, `edge_idx`)
+/// sliced by a `row` prefix-offset table, so `out_edges` is a contiguous
+/// slice with no per-node indirection -- useful once a graph is finalized
+/// and only traversed.
+#[derive(Debug, Default, Clone)]
+pub struct Csr {
+    pub(super) row: Vec<usize>,
+    pub(super) target: Vec<NodeIdx>,
+    pub(super) edge_idx: Vec<EdgeIdx>,
+}
+
+impl AdjacencyList for Csr {
+    type Edges<'a>
+        = std::iter::Zip<
+        std::iter::Copied<std::slice::Iter<'a, NodeIdx>>,
+        std::iter::Copied<std::slice::Iter<'a, EdgeIdx>>,
+    >
+    where
+        Self: 'a;
+
+    fn n_nodes(&self) -> usize {
+        self.row.len() - 1
+    }
+
+    fn out_edges(&self, node: NodeIdx) -> Self::Edges<'_> {
+        let range = self.row[node]..self.row[node + 1];
+        self.target[range.clone()]
+            .iter()
+            .copied()
+            .zip(self.edge_idx[range].iter().copied())
+    }
+}
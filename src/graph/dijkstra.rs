@@ -9,7 +9,7 @@ pub struct Dijkstra<T: PartialEq + PartialOrd> {
     root: NodeIdx,
     distance: Vec<Option<T>>,
     through: Vec<Option<EdgeIdx>>,
-    next: BinaryHeap<(Reverse<TotalOrd<T>>, EdgeIdx)>,
+    next: BinaryHeap<(Reverse<TotalOrd<T>>, NodeIdx)>,
 }
 
 impl<T: Default + PartialEq + PartialOrd + Clone + Copy + From<i32> + Add<Output = T>> Dijkstra<T> {
@@ -68,8 +68,8 @@ impl<T: Default + PartialEq + PartialOrd + Clone + Copy + From<i32> + Add<Output
     ///     assert_eq!(through[3], None);
     /// }
     /// ```
-    pub fn with_graph<D, F: Fn(EdgeIdx) -> T>(
-        graph: &Graph<D>,
+    pub fn with_graph<G: AdjacencyList, F: Fn(EdgeIdx) -> T>(
+        graph: &G,
         root: NodeIdx,
         edge_dist: F,
     ) -> Self {
@@ -80,10 +80,14 @@ impl<T: Default + PartialEq + PartialOrd + Clone + Copy + From<i32> + Add<Output
 
     /// Run Dijkstra with graph and root node.
     ///
+    /// Accepts either a [`Graph`] or its [`Csr`] view through the
+    /// [`AdjacencyList`] trait, so traversals can run over whichever
+    /// representation suits the input size.
+    ///
     /// **Complexity** `O((E+V)logV)`
-    pub fn read<D, F: Fn(EdgeIdx) -> T>(
+    pub fn read<G: AdjacencyList, F: Fn(EdgeIdx) -> T>(
         &mut self,
-        graph: &Graph<D>,
+        graph: &G,
         root: NodeIdx,
         edge_distance: F,
     ) {
@@ -96,30 +100,24 @@ impl<T: Default + PartialEq + PartialOrd + Clone + Copy + From<i32> + Add<Output
         self.through.resize(graph.n_nodes(), None);
 
         self.distance[root] = Some(T::from(0));
-        for (edge, edge_idx) in graph.out_edges(root) {
-            let dist = edge_distance(*edge_idx);
-            self.distance[edge.target] = Some(dist);
-            self.through[edge.target] = Some(*edge_idx);
-            self.next.push((Reverse(TotalOrd(dist)), *edge_idx));
-        }
+        self.next.push((Reverse(TotalOrd(T::from(0))), root));
 
-        let edges = graph.edges();
-        while let Some((Reverse(TotalOrd(dist)), curr)) = self.next.pop() {
-            if let Some(prev_dist) = self.distance[edges[curr].target] {
+        while let Some((Reverse(TotalOrd(dist)), node)) = self.next.pop() {
+            if let Some(prev_dist) = self.distance[node] {
                 if prev_dist < dist {
                     continue;
                 }
             }
-            for (next, next_idx) in graph.out_edges(edges[curr].target) {
-                let next_dist = dist + edge_distance(*next_idx);
-                if let Some(prev_dist) = self.distance[next.target] {
+            for (target, edge_idx) in graph.out_edges(node) {
+                let next_dist = dist + edge_distance(edge_idx);
+                if let Some(prev_dist) = self.distance[target] {
                     if prev_dist <= next_dist {
                         continue;
                     }
                 }
-                self.distance[next.target] = Some(next_dist);
-                self.through[next.target] = Some(*next_idx);
-                self.next.push((Reverse(TotalOrd(next_dist)), *next_idx));
+                self.distance[target] = Some(next_dist);
+                self.through[target] = Some(edge_idx);
+                self.next.push((Reverse(TotalOrd(next_dist)), target));
             }
         }
     }
@@ -0,0 +1,196 @@
+use super::*;
+
+/// Whether [`HeavyLightDecomposition::path_segments`] ranges cover vertex
+/// values or edge values.
+///
+/// In `Edge` mode, an edge's value is attributed to its deeper endpoint's
+/// `id`, so a path's topmost vertex (the LCA) is excluded from the range.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DecomposeMode {
+    #[default]
+    Vertex,
+    Edge,
+}
+
+/// Heavy-Light Decomposition over a tree.
+///
+/// Splits a tree into chains so any root-to-node path crosses `O(log n)`
+/// chains, and assigns each node a contiguous `id` such that every heavy
+/// chain occupies a consecutive range. Feeding those ranges to a range data
+/// structure (e.g. [`FenwickTree`](crate::ds::FenwickTree)) keyed by `id`
+/// answers path aggregate/update queries in `O(log^2 n)`.
+#[derive(Debug, Default)]
+pub struct HeavyLightDecomposition {
+    parent: Vec<Option<NodeIdx>>,
+    depth: Vec<usize>,
+    size: Vec<usize>,
+    heavy: Vec<Option<NodeIdx>>,
+    head: Vec<NodeIdx>,
+    id: Vec<usize>,
+    mode: DecomposeMode,
+}
+
+impl HeavyLightDecomposition {
+    /// Get the contiguous DFS index of a node.
+    ///
+    /// **Complexity** `O(1)`
+    pub fn id(&self, node: NodeIdx) -> usize {
+        self.id[node]
+    }
+
+    /// Get the parent of a node, or `None` for the root.
+    ///
+    /// **Complexity** `O(1)`
+    pub fn parent(&self, node: NodeIdx) -> Option<NodeIdx> {
+        self.parent[node]
+    }
+
+    /// Get the depth of a node, with the root at depth `0`.
+    ///
+    /// **Complexity** `O(1)`
+    pub fn depth(&self, node: NodeIdx) -> usize {
+        self.depth[node]
+    }
+
+    /// Build a Heavy-Light Decomposition of a tree, rooted at `root`.
+    ///
+    /// **Complexity** `O(n)`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use procon::graph::*;
+    ///
+    /// fn main() {
+    ///     let mut graph = UnGraph::with_nodes(5);
+    ///     graph.add_edge(Edge::new(0, 1));
+    ///     graph.add_edge(Edge::new(1, 2));
+    ///     graph.add_edge(Edge::new(1, 3));
+    ///     graph.add_edge(Edge::new(0, 4));
+    ///
+    ///     let hld = HeavyLightDecomposition::with_graph(&graph, 0, DecomposeMode::Vertex);
+    ///
+    ///     assert_eq!(hld.lca(2, 3), 1);
+    ///     assert_eq!(hld.lca(2, 4), 0);
+    /// }
+    /// ```
+    pub fn with_graph(graph: &UnGraph, root: NodeIdx, mode: DecomposeMode) -> Self {
+        let mut algo = Self::default();
+        algo.read(graph, root, mode);
+        algo
+    }
+
+    /// Build a Heavy-Light Decomposition of a tree, rooted at `root`.
+    ///
+    /// **Complexity** `O(n)`
+    pub fn read(&mut self, graph: &UnGraph, root: NodeIdx, mode: DecomposeMode) {
+        let n = graph.n_nodes();
+        self.parent = vec![None; n];
+        self.depth = vec![0; n];
+        self.size = vec![1; n];
+        self.heavy = vec![None; n];
+        self.head = vec![root; n];
+        self.id = vec![0; n];
+        self.mode = mode;
+
+        // First pass: parent, depth and subtree size, via an iterative
+        // DFS so deep trees don't overflow the call stack.
+        let mut order = Vec::with_capacity(n);
+        let mut visited = vec![false; n];
+        let mut stack = vec![root];
+        visited[root] = true;
+        while let Some(node) = stack.pop() {
+            order.push(node);
+            for (edge, _) in graph.out_edges(node) {
+                if !visited[edge.target] {
+                    visited[edge.target] = true;
+                    self.parent[edge.target] = Some(node);
+                    self.depth[edge.target] = self.depth[node] + 1;
+                    stack.push(edge.target);
+                }
+            }
+        }
+        for &node in order.iter().rev() {
+            if let Some(p) = self.parent[node] {
+                self.size[p] += self.size[node];
+                if self.heavy[p].is_none() || self.size[self.heavy[p].unwrap()] < self.size[node] {
+                    self.heavy[p] = Some(node);
+                }
+            }
+        }
+
+        // Second pass: assign contiguous ids, visiting the heavy child
+        // first so each heavy chain occupies a consecutive range.
+        let mut next_id = 0usize;
+        let mut stack = vec![(root, root)];
+        while let Some((node, head)) = stack.pop() {
+            self.head[node] = head;
+            self.id[node] = next_id;
+            next_id += 1;
+
+            let mut light_children = Vec::new();
+            for (edge, _) in graph.out_edges(node) {
+                let child = edge.target;
+                if self.parent[child] != Some(node) || self.heavy[node] == Some(child) {
+                    continue;
+                }
+                light_children.push(child);
+            }
+            for child in light_children {
+                stack.push((child, child));
+            }
+            if let Some(child) = self.heavy[node] {
+                stack.push((child, head));
+            }
+        }
+    }
+
+    /// Get the lowest common ancestor of `u` and `v`.
+    ///
+    /// **Complexity** `O(log n)`
+    pub fn lca(&self, mut u: NodeIdx, mut v: NodeIdx) -> NodeIdx {
+        loop {
+            if self.head[u] == self.head[v] {
+                return if self.depth[u] < self.depth[v] { u } else { v };
+            }
+            if self.depth[self.head[u]] < self.depth[self.head[v]] {
+                std::mem::swap(&mut u, &mut v);
+            }
+            u = self.parent[self.head[u]].unwrap();
+        }
+    }
+
+    /// Get the `[l, r]` (inclusive) `id` ranges covering the path between
+    /// `u` and `v`.
+    ///
+    /// These ranges are meant to feed a range data structure keyed by
+    /// `id`; in [`DecomposeMode::Edge`] mode, the range excludes the `id`
+    /// of the path's topmost vertex (the LCA), since that vertex has no
+    /// edge of its own on the path.
+    ///
+    /// **Complexity** `O(log n)`
+    pub fn path_segments(&self, mut u: NodeIdx, mut v: NodeIdx) -> Vec<(usize, usize)> {
+        let mut segments = Vec::new();
+        while self.head[u] != self.head[v] {
+            if self.depth[self.head[u]] < self.depth[self.head[v]] {
+                std::mem::swap(&mut u, &mut v);
+            }
+            segments.push((self.id[self.head[u]], self.id[u]));
+            u = self.parent[self.head[u]].unwrap();
+        }
+
+        let (lo, hi) = if self.id[u] <= self.id[v] {
+            (u, v)
+        } else {
+            (v, u)
+        };
+        let lo_id = match self.mode {
+            DecomposeMode::Vertex => self.id[lo],
+            DecomposeMode::Edge => self.id[lo] + 1,
+        };
+        if lo_id <= self.id[hi] {
+            segments.push((lo_id, self.id[hi]));
+        }
+        segments
+    }
+}
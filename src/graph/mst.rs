@@ -0,0 +1,56 @@
+use super::*;
+use crate::{TotalOrd, DSU};
+
+/// Build a minimum spanning tree with Kruskal's algorithm.
+///
+/// Edge selection stops once `n_nodes - 1` edges have been accepted. If the
+/// graph is disconnected, every edge is examined and the result is a
+/// minimum spanning forest.
+///
+/// **Complexity** `O(E log E)`
+///
+/// # Example
+///
+/// ```
+/// use procon::graph::*;
+///
+/// fn main() {
+///     let mut graph = UnGraph::with_nodes(4);
+///     graph.add_edge(Edge::new(0, 1));
+///     let e1 = graph.add_edge(Edge::new(1, 2));
+///     let e2 = graph.add_edge(Edge::new(0, 2));
+///     graph.add_edge(Edge::new(2, 3));
+///     let weight = |e: EdgeIdx| [3, 1, 1, 5][e];
+///
+///     let (edges, total) = kruskal(&graph, weight);
+///
+///     assert_eq!(total, 7);
+///     assert_eq!(edges, vec![e1, e2, 3]);
+/// }
+/// ```
+pub fn kruskal<T, F>(graph: &UnGraph, edge_weight: F) -> (Vec<EdgeIdx>, T)
+where
+    T: Default + PartialOrd + Copy + std::ops::Add<Output = T>,
+    F: Fn(EdgeIdx) -> T,
+{
+    let mut order: Vec<EdgeIdx> = (0..graph.n_edges()).collect();
+    order.sort_by_key(|&edge_idx| TotalOrd(edge_weight(edge_idx)));
+
+    let mut dsu = DSU::with_capacity(graph.n_nodes());
+    let mut selected = Vec::new();
+    let mut total = T::default();
+
+    let edges = graph.edges();
+    for edge_idx in order {
+        let edge = edges[edge_idx];
+        if dsu.merge(edge.source, edge.target).is_some() {
+            selected.push(edge_idx);
+            total = total + edge_weight(edge_idx);
+            if selected.len() + 1 == graph.n_nodes() {
+                break;
+            }
+        }
+    }
+
+    (selected, total)
+}
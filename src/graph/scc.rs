@@ -0,0 +1,149 @@
+use std::collections::HashSet;
+
+use super::*;
+
+/// Tarjan's strongly connected components algorithm, with an optional DAG
+/// condensation.
+#[derive(Debug, Default)]
+pub struct SCC {
+    /// Components, in reverse topological order.
+    components: Vec<Vec<NodeIdx>>,
+    /// Maps each node to the index of its component in `components`.
+    component_id: Vec<usize>,
+}
+
+impl SCC {
+    /// Get the components, in reverse topological order.
+    ///
+    /// **Complexity** `O(1)`
+    pub fn components(&self) -> &Vec<Vec<NodeIdx>> {
+        &self.components
+    }
+
+    /// Get the component id of each node.
+    ///
+    /// **Complexity** `O(1)`
+    pub fn component_id(&self) -> &Vec<usize> {
+        &self.component_id
+    }
+
+    /// Run Tarjan's algorithm on a directed graph.
+    ///
+    /// Uses an explicit stack instead of recursion so deep graphs don't
+    /// overflow the call stack.
+    ///
+    /// **Complexity** `O(V+E)`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use procon::graph::*;
+    ///
+    /// fn main() {
+    ///     let mut graph = DiGraph::with_nodes(4);
+    ///     graph.add_edge(Edge::new(0, 1));
+    ///     graph.add_edge(Edge::new(1, 0));
+    ///     graph.add_edge(Edge::new(1, 2));
+    ///     graph.add_edge(Edge::new(2, 3));
+    ///
+    ///     let scc = SCC::with_graph(&graph);
+    ///
+    ///     assert_eq!(scc.component_id()[0], scc.component_id()[1]);
+    ///     assert_ne!(scc.component_id()[1], scc.component_id()[2]);
+    ///     assert_ne!(scc.component_id()[2], scc.component_id()[3]);
+    /// }
+    /// ```
+    pub fn with_graph(graph: &DiGraph) -> Self {
+        let mut algo = Self::default();
+        algo.read(graph);
+        algo
+    }
+
+    /// Run Tarjan's algorithm on a directed graph.
+    ///
+    /// **Complexity** `O(V+E)`
+    pub fn read(&mut self, graph: &DiGraph) {
+        let n = graph.n_nodes();
+        self.components.clear();
+        self.component_id.clear();
+        self.component_id.resize(n, 0);
+
+        let adj: Vec<Vec<NodeIdx>> = (0..n)
+            .map(|node| graph.out_edges(node).map(|(edge, _)| edge.target).collect())
+            .collect();
+
+        let mut index: Vec<Option<usize>> = vec![None; n];
+        let mut lowlink = vec![0usize; n];
+        let mut on_stack = vec![false; n];
+        let mut node_stack: Vec<NodeIdx> = Vec::new();
+        let mut next_index = 0usize;
+
+        // Explicit DFS stack: (node, next out-edge position to visit).
+        let mut work: Vec<(NodeIdx, usize)> = Vec::new();
+
+        for start in 0..n {
+            if index[start].is_some() {
+                continue;
+            }
+            work.push((start, 0));
+
+            while let Some(&(node, pos)) = work.last() {
+                if pos == 0 {
+                    index[node] = Some(next_index);
+                    lowlink[node] = next_index;
+                    next_index += 1;
+                    node_stack.push(node);
+                    on_stack[node] = true;
+                }
+
+                if pos < adj[node].len() {
+                    work.last_mut().unwrap().1 += 1;
+                    let next = adj[node][pos];
+                    if index[next].is_none() {
+                        work.push((next, 0));
+                    } else if on_stack[next] {
+                        lowlink[node] = lowlink[node].min(index[next].unwrap());
+                    }
+                } else {
+                    work.pop();
+                    if let Some(&(parent, _)) = work.last() {
+                        lowlink[parent] = lowlink[parent].min(lowlink[node]);
+                    }
+
+                    if lowlink[node] == index[node].unwrap() {
+                        let mut component = Vec::new();
+                        loop {
+                            let v = node_stack.pop().unwrap();
+                            on_stack[v] = false;
+                            self.component_id[v] = self.components.len();
+                            component.push(v);
+                            if v == node {
+                                break;
+                            }
+                        }
+                        self.components.push(component);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Build the condensation: a DAG with one node per component and an
+    /// edge whenever an original edge crosses components, deduplicated.
+    ///
+    /// **Complexity** `O(V+E)`
+    pub fn condensation(&self, graph: &DiGraph) -> DiGraph {
+        let mut seen = HashSet::new();
+        let mut edges = Vec::new();
+        for edge in graph.edges() {
+            let (cs, ct) = (
+                self.component_id[edge.source],
+                self.component_id[edge.target],
+            );
+            if cs != ct && seen.insert((cs, ct)) {
+                edges.push(Edge::new(cs, ct));
+            }
+        }
+        DiGraph::with_edges(self.components.len(), edges.into_iter())
+    }
+}
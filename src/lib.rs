@@ -1,5 +1,10 @@
 mod total_ord;
 
+pub mod ds;
+pub mod graph;
+pub mod math;
+
+pub use ds::*;
 pub use total_ord::*;
 
 #[doc(hidden)]
@@ -7,6 +12,9 @@ pub use std::io::*;
 
 /// Receive inputs up to EOF.
 ///
+/// A `source = "..."` form reads directly from a string literal instead of
+/// a `Read`er, which is handy for tests.
+///
 /// # Examples
 ///
 /// ```
@@ -22,10 +30,22 @@ pub use std::io::*;
 ///     assert_eq!(b, -3);
 ///     assert_eq!(c, 4.5);
 ///     assert_eq!(x, vec![0, 1, 2, 3]);
+///
+///     read_to_end!(source = "4 -3 4.5\n0 1 2 3 4 5", a: usize, b: i32, c: f64, x: [i32; a]);
+///
+///     assert_eq!(a, 4);
+///     assert_eq!(b, -3);
+///     assert_eq!(c, 4.5);
+///     assert_eq!(x, vec![0, 1, 2, 3]);
 /// }
 /// ```
 #[macro_export]
 macro_rules! read_to_end {
+    (source = $s:expr, $($r:tt)*) => {
+        let mut iter = ($s).split_whitespace();
+        read_iterator!(iter, $($r)*);
+    };
+
     ($s:expr, $($r:tt)*) => {
         let mut buf: Vec<u8> = Vec::new();
         ($s).read_to_end(&mut buf).unwrap_or_else(|_| panic!("failed to read_to_end"));
@@ -36,6 +56,9 @@ macro_rules! read_to_end {
 
 /// Receives a specified number of lines of input.
 ///
+/// A `source = "..."` form reads directly from a string literal instead of
+/// a `Read`er, which is handy for tests.
+///
 /// # Examples
 ///
 /// ```
@@ -51,10 +74,22 @@ macro_rules! read_to_end {
 ///     assert_eq!(b, -3);
 ///     assert_eq!(c, 4.5);
 ///     assert_eq!(x, vec![0, 1, 2, 3]);
+///
+///     read_lines!(source = "4 -3 4.5\n0 1 2 3 4 5", 2, a: usize, b: i32, c: f64, x: [i32; a]);
+///
+///     assert_eq!(a, 4);
+///     assert_eq!(b, -3);
+///     assert_eq!(c, 4.5);
+///     assert_eq!(x, vec![0, 1, 2, 3]);
 /// }
 /// ```
 #[macro_export]
 macro_rules! read_lines {
+    (source = $s:expr, $n:expr, $($r:tt)*) => {
+        let mut iter = ($s).lines().take($n).flat_map(|line| line.split_whitespace());
+        read_iterator!(iter, $($r)*);
+    };
+
     ($s:expr, $n:expr, $($r:tt)*) => {
         let mut buf: Vec<u8> = Vec::new();
         for _ in 0..($n) {
@@ -65,6 +100,32 @@ macro_rules! read_lines {
     };
 }
 
+/// Run a `fn(impl BufRead, impl Write)` solver against a literal input and
+/// assert its captured output.
+///
+/// # Examples
+///
+/// ```
+/// use procon::*;
+///
+/// fn solve(mut reader: impl std::io::BufRead, mut writer: impl std::io::Write) {
+///     read_to_end!(reader, a: i32, b: i32);
+///     writeln!(writer, "{}", a + b).unwrap();
+/// }
+///
+/// fn main() {
+///     assert_solve!(solve, "1 2", "3\n");
+/// }
+/// ```
+#[macro_export]
+macro_rules! assert_solve {
+    ($solver:expr, $input:expr, $expected:expr) => {{
+        let mut output: Vec<u8> = Vec::new();
+        $solver(std::io::BufReader::new(($input).as_bytes()), &mut output);
+        assert_eq!(std::str::from_utf8(&output).unwrap(), $expected);
+    }};
+}
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! read_iterator {
@@ -93,6 +154,14 @@ macro_rules! read_value {
         read_value!($iter, String).chars().collect::<Vec<char>>()
     };
 
+    ($iter:expr, usize1) => {
+        read_value!($iter, usize) - 1
+    };
+
+    ($iter:expr, isize1) => {
+        read_value!($iter, isize) - 1
+    };
+
     ($iter:expr, $t:ty) => {
         $iter.next()
             .unwrap_or_else(|| panic!("failed to next"))
@@ -101,6 +170,178 @@ macro_rules! read_value {
     };
 }
 
+/// Update `$x` in place to the maximum of itself and the given values.
+///
+/// # Examples
+///
+/// ```
+/// use procon::chmax;
+///
+/// fn main() {
+///     let mut x = 1;
+///     chmax!(x, 3, 2);
+///     assert_eq!(x, 3);
+/// }
+/// ```
+#[macro_export]
+macro_rules! chmax {
+    ($x:expr, $v:expr) => {
+        $x = std::cmp::max($x, $v);
+    };
+    ($x:expr, $v:expr, $($rest:expr),+) => {
+        $crate::chmax!($x, $v);
+        $crate::chmax!($x, $($rest),+);
+    };
+}
+
+/// Update `$x` in place to the minimum of itself and the given values.
+///
+/// # Examples
+///
+/// ```
+/// use procon::chmin;
+///
+/// fn main() {
+///     let mut x = 3;
+///     chmin!(x, 1, 2);
+///     assert_eq!(x, 1);
+/// }
+/// ```
+#[macro_export]
+macro_rules! chmin {
+    ($x:expr, $v:expr) => {
+        $x = std::cmp::min($x, $v);
+    };
+    ($x:expr, $v:expr, $($rest:expr),+) => {
+        $crate::chmin!($x, $v);
+        $crate::chmin!($x, $($rest),+);
+    };
+}
+
+/// Get the maximum of an arbitrary number of values.
+///
+/// # Examples
+///
+/// ```
+/// use procon::max;
+///
+/// fn main() {
+///     assert_eq!(max!(1, 3, 2), 3);
+/// }
+/// ```
+#[macro_export]
+macro_rules! max {
+    ($a:expr) => {
+        $a
+    };
+    ($a:expr, $($rest:expr),+) => {
+        std::cmp::max($a, $crate::max!($($rest),+))
+    };
+}
+
+/// Get the minimum of an arbitrary number of values.
+///
+/// # Examples
+///
+/// ```
+/// use procon::min;
+///
+/// fn main() {
+///     assert_eq!(min!(3, 1, 2), 1);
+/// }
+/// ```
+#[macro_export]
+macro_rules! min {
+    ($a:expr) => {
+        $a
+    };
+    ($a:expr, $($rest:expr),+) => {
+        std::cmp::min($a, $crate::min!($($rest),+))
+    };
+}
+
+/// Build nested `Vec`s with per-dimension lengths and a fill value.
+///
+/// The fill expression is cloned into every innermost cell.
+///
+/// # Examples
+///
+/// ```
+/// use procon::dvec;
+///
+/// fn main() {
+///     assert_eq!(dvec!(0; 3), vec![0, 0, 0]);
+///     assert_eq!(dvec!(0; 2, 3), vec![vec![0, 0, 0], vec![0, 0, 0]]);
+/// }
+/// ```
+#[macro_export]
+macro_rules! dvec {
+    ($v:expr; $len:expr) => {
+        vec![$v; $len]
+    };
+    ($v:expr; $len:expr, $($rest:expr),+) => {
+        vec![$crate::dvec!($v; $($rest),+); $len]
+    };
+}
+
+/// Join a slice into a single `writeln!`, instead of one `write!` per
+/// element.
+///
+/// # Examples
+///
+/// ```
+/// use procon::print_vec;
+/// use std::io::Write;
+///
+/// fn main() {
+///     let mut out: Vec<u8> = Vec::new();
+///     let v = vec![1, 2, 3];
+///     print_vec!(out, v, " ");
+///     assert_eq!(out, b"1 2 3\n");
+/// }
+/// ```
+#[macro_export]
+macro_rules! print_vec {
+    ($writer:expr, $values:expr, $sep:expr) => {{
+        let joined = $values
+            .iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>()
+            .join($sep);
+        writeln!($writer, "{}", joined).unwrap_or_else(|_| panic!("failed to write"));
+    }};
+}
+
+/// Accumulate writes made to `$buf` (a `String`) inside the block, then
+/// flush them to `$writer` in a single `write_all`.
+///
+/// # Examples
+///
+/// ```
+/// use procon::flush_print;
+/// use std::fmt::Write as _;
+/// use std::io::Write as _;
+///
+/// fn main() {
+///     let mut out: Vec<u8> = Vec::new();
+///     flush_print!(out, buf => {
+///         for i in 0..3 {
+///             writeln!(buf, "{}", i).unwrap();
+///         }
+///     });
+///     assert_eq!(out, b"0\n1\n2\n");
+/// }
+/// ```
+#[macro_export]
+macro_rules! flush_print {
+    ($writer:expr, $buf:ident => $body:block) => {{
+        let mut $buf = String::new();
+        $body($writer)
+            .write_all($buf.as_bytes())
+            .unwrap_or_else(|_| panic!("failed to write_all"));
+    }};
+}
+
 #[cfg(test)]
 mod tests {
     use crate::*;
@@ -125,4 +366,79 @@ mod tests {
 
         assert_eq!(a, vec!['H', 'e', 'l', 'l', 'o', '!']);
     }
+
+    #[test]
+    fn test_read_one_indexed() {
+        let source = "3 -4 1 2 3";
+        let mut reader = std::io::BufReader::new(source.as_bytes());
+
+        read_to_end!(reader, u: usize1, v: isize1, w: [usize1; 3]);
+
+        assert_eq!(u, 2);
+        assert_eq!(v, -5);
+        assert_eq!(w, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_read_source() {
+        read_to_end!(source = "4 -3 4.5", a: usize, b: i32, c: f64);
+
+        assert_eq!(a, 4);
+        assert_eq!(b, -3);
+        assert_eq!(c, 4.5);
+    }
+
+    #[test]
+    fn test_assert_solve() {
+        fn solve(mut reader: impl std::io::BufRead, mut writer: impl std::io::Write) {
+            read_to_end!(reader, a: i32, b: i32);
+            writeln!(writer, "{}", a + b).unwrap();
+        }
+
+        assert_solve!(solve, "1 2", "3\n");
+    }
+
+    #[test]
+    fn test_chmax_chmin() {
+        let mut x = 1;
+        chmax!(x, 3, 2);
+        assert_eq!(x, 3);
+
+        let mut y = 3;
+        chmin!(y, 1, 2);
+        assert_eq!(y, 1);
+    }
+
+    #[test]
+    fn test_max_min() {
+        assert_eq!(max!(1, 3, 2), 3);
+        assert_eq!(min!(3, 1, 2), 1);
+    }
+
+    #[test]
+    fn test_dvec() {
+        assert_eq!(dvec!(0; 3), vec![0, 0, 0]);
+        assert_eq!(dvec!(0; 2, 3), vec![vec![0, 0, 0], vec![0, 0, 0]]);
+    }
+
+    #[test]
+    fn test_print_vec() {
+        let mut out: Vec<u8> = Vec::new();
+        let v = [1, 2, 3];
+        print_vec!(out, v, " ");
+        assert_eq!(out, b"1 2 3\n");
+    }
+
+    #[test]
+    fn test_flush_print() {
+        use std::fmt::Write as _;
+
+        let mut out: Vec<u8> = Vec::new();
+        flush_print!(out, buf => {
+            for i in 0..3 {
+                writeln!(buf, "{}", i).unwrap();
+            }
+        });
+        assert_eq!(out, b"0\n1\n2\n");
+    }
 }
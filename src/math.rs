@@ -1,14 +1,16 @@
 pub mod gcd;
+pub mod modular;
 
 use std::ops::*;
 
 pub use gcd::*;
+pub use modular::*;
 
 /// Defines an additive identity element for `Self`.
 ///
 /// # Laws
 ///
-/// ```{.text}
+/// ```text
 /// a + 0 = a       ∀ a ∈ Self
 /// 0 + a = a       ∀ a ∈ Self
 /// ```
@@ -63,7 +65,7 @@ impl_zero!(f64, 0.0);
 ///
 /// # Laws
 ///
-/// ```{.text}
+/// ```text
 /// a * 1 = a       ∀ a ∈ Self
 /// 1 * a = a       ∀ a ∈ Self
 /// ```
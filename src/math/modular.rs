@@ -0,0 +1,76 @@
+use super::*;
+
+/// Compute `base.pow(exp) % m` by binary exponentiation.
+///
+/// **Complexity** `O(log(exp))`
+///
+/// # Example
+///
+/// ```
+/// use procon::math::mod_pow;
+/// assert_eq!(mod_pow(2, 10, 1_000_000_007), 1024);
+/// ```
+pub fn mod_pow(base: i64, mut exp: i64, m: i64) -> i64 {
+    let mut base = base.rem_euclid(m);
+    let mut result = 1i64 % m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = (result as i128 * base as i128 % m as i128) as i64;
+        }
+        base = (base as i128 * base as i128 % m as i128) as i64;
+        exp >>= 1;
+    }
+    result
+}
+
+/// Compute the modular inverse of `a` modulo `m` with the extended
+/// Euclidean algorithm, or `None` if `gcd(a, m) != 1`.
+///
+/// **Complexity** `O(log(min(a, m)))`
+///
+/// # Example
+///
+/// ```
+/// use procon::math::mod_inverse;
+/// assert_eq!(mod_inverse(3, 7), Some(5));
+/// assert_eq!(mod_inverse(2, 4), None);
+/// ```
+pub fn mod_inverse(a: i64, m: i64) -> Option<i64> {
+    if gcd(a, m) != 1 {
+        return None;
+    }
+    let (x, _) = extgcd(a, m);
+    Some(((x % m) + m) % m)
+}
+
+/// Solve a system of simultaneous congruences `x = r_i (mod m_i)` by
+/// merging them pairwise with [`extgcd`], returning `(r, lcm)` such that any solution is
+/// `x = r (mod lcm)`, or `None` if the system is inconsistent.
+///
+/// Intermediate products are carried in `i128` to avoid overflowing `i64`.
+///
+/// **Complexity** `O(n log(max m_i))`
+///
+/// # Example
+///
+/// ```
+/// use procon::math::crt;
+/// assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+/// assert_eq!(crt(&[(0, 2), (1, 2)]), None);
+/// ```
+pub fn crt(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+    let (mut r0, mut m0) = (0i128, 1i128);
+    for &(r1, m1) in congruences {
+        let (r1, m1) = (r1 as i128, m1 as i128);
+        let g = gcd(m0, m1);
+        if (r1 - r0) % g != 0 {
+            return None;
+        }
+        let (p, _) = extgcd(m0 / g, m1 / g);
+        let lcm = m0 / g * m1;
+        let tmp = (r1 - r0) / g * p % (m1 / g);
+        r0 = (r0 + m0 * tmp).rem_euclid(lcm);
+        m0 = lcm;
+    }
+    Some((r0 as i64, m0 as i64))
+}